@@ -0,0 +1,221 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! A [wyhash](https://github.com/wangyi-fudan/wyhash)-compatible
+//! [Hasher](core::hash::Hasher), for use with [HashMap](std::collections::HashMap)
+//! and friends.
+//!
+//! This module is only available when the `hash` feature is enabled.
+use core::{
+	fmt::Debug,
+	hash::{BuildHasher, Hasher},
+};
+
+use crate::util::{wymix, P0, P1};
+
+/// A [wyhash](https://github.com/wangyi-fudan/wyhash)-compatible implementation
+/// of [core::hash::Hasher].
+///
+/// ## Examples
+///
+/// ```rust
+/// use core::hash::{BuildHasher, Hash, Hasher};
+///
+/// use rand_wyrand::{WyHash, WyHashBuilder};
+///
+/// let mut hasher = WyHash::with_seed(0);
+/// "hello world".hash(&mut hasher);
+/// let hash = hasher.finish();
+///
+/// let builder = WyHashBuilder::new(0);
+/// let mut hasher = builder.build_hasher();
+/// "hello world".hash(&mut hasher);
+/// assert_eq!(hash, hasher.finish());
+/// ```
+#[derive(Clone)]
+pub struct WyHash {
+	seed: u64,
+	len: u64,
+	buf: [u8; 16],
+	buf_len: usize,
+}
+
+impl WyHash {
+	/// Creates a new [WyHash] using the given seed.
+	#[inline]
+	#[must_use]
+	pub const fn with_seed(seed: u64) -> Self {
+		Self {
+			seed: seed ^ P0,
+			len: 0,
+			buf: [0; 16],
+			buf_len: 0,
+		}
+	}
+
+	/// Mixes a full 16-byte block into the running seed.
+	#[inline]
+	fn consume_block(&mut self, block: &[u8; 16]) {
+		let lo = u64::from_le_bytes(block[0..8].try_into().unwrap());
+		let hi = u64::from_le_bytes(block[8..16].try_into().unwrap());
+		self.seed = wymix(lo ^ P1, hi ^ self.seed);
+	}
+}
+
+impl Default for WyHash {
+	/// Creates a new [WyHash] seeded with `0`.
+	#[inline]
+	fn default() -> Self {
+		Self::with_seed(0)
+	}
+}
+
+// Custom Debug implementation that does not expose the internal state, same
+// as WyRand.
+impl Debug for WyHash {
+	fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+		f.debug_tuple("WyHash").finish()
+	}
+}
+
+/// Reads a 1..=3 byte trailing block the way wyhash's `wyr3` does.
+#[inline]
+fn wyr3(buf: &[u8]) -> u64 {
+	let k = buf.len();
+	((buf[0] as u64) << 16) | ((buf[k >> 1] as u64) << 8) | (buf[k - 1] as u64)
+}
+
+/// Reads a little-endian `u32` the way wyhash's `wyr4` does.
+#[inline]
+fn wyr4(buf: &[u8]) -> u64 {
+	u32::from_le_bytes(buf[0..4].try_into().unwrap()) as u64
+}
+
+/// Reads a little-endian `u64` the way wyhash's `wyr8` does.
+#[inline]
+fn wyr8(buf: &[u8]) -> u64 {
+	u64::from_le_bytes(buf[0..8].try_into().unwrap())
+}
+
+/// Splits a 1..=16 byte trailing block into the two words wyhash mixes in at
+/// the end, using the classic `wyr3`/`wyr4`/`wyr8` tail reads.
+#[inline]
+fn tail_words(buf: &[u8]) -> (u64, u64) {
+	match buf.len() {
+		0 => (0, 0),
+		1..=3 => (wyr3(buf), 0),
+		4..=8 => (wyr4(&buf[..4]), wyr4(&buf[buf.len() - 4..])),
+		_ => (wyr8(&buf[..8]), wyr8(&buf[buf.len() - 8..])),
+	}
+}
+
+impl Hasher for WyHash {
+	fn write(&mut self, mut bytes: &[u8]) {
+		self.len += bytes.len() as u64;
+		while !bytes.is_empty() {
+			if self.buf_len == 16 {
+				let block = self.buf;
+				self.consume_block(&block);
+				self.buf_len = 0;
+			}
+			let space = 16 - self.buf_len;
+			let take = space.min(bytes.len());
+			self.buf[self.buf_len..self.buf_len + take].copy_from_slice(&bytes[..take]);
+			self.buf_len += take;
+			bytes = &bytes[take..];
+		}
+	}
+
+	#[inline]
+	fn finish(&self) -> u64 {
+		let mut seed = self.seed;
+		if self.buf_len > 0 {
+			let (a, b) = tail_words(&self.buf[..self.buf_len]);
+			seed = wymix(a ^ P1, b ^ seed);
+		}
+		wymix(seed ^ self.len, P1)
+	}
+}
+
+/// A [BuildHasher] that produces [WyHash] hashers seeded with a fixed seed.
+///
+/// ## Examples
+///
+/// ```rust,ignore
+/// // `hashbrown` (or `std`'s `HashMap::with_hasher`) accepts any `BuildHasher`.
+/// use hashbrown::HashMap;
+/// use rand_wyrand::WyHashBuilder;
+///
+/// let mut map = HashMap::with_hasher(WyHashBuilder::new(0));
+/// map.insert("foo", 42);
+/// assert_eq!(map.get("foo"), Some(&42));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WyHashBuilder {
+	seed: u64,
+}
+
+impl WyHashBuilder {
+	/// Creates a new [WyHashBuilder] that seeds every [WyHash] it builds with
+	/// `seed`.
+	#[inline]
+	#[must_use]
+	pub const fn new(seed: u64) -> Self {
+		Self { seed }
+	}
+}
+
+impl BuildHasher for WyHashBuilder {
+	type Hasher = WyHash;
+
+	#[inline]
+	fn build_hasher(&self) -> WyHash {
+		WyHash::with_seed(self.seed)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate alloc;
+
+	use alloc::format;
+	use core::hash::Hasher;
+
+	use super::*;
+
+	#[test]
+	fn no_leaking_debug() {
+		let hasher = WyHash::with_seed(1234);
+
+		assert_eq!(format!("{:?}", hasher), "WyHash");
+	}
+
+	#[test]
+	fn empty_input_is_stable() {
+		let mut hasher = WyHash::with_seed(0);
+		hasher.write(&[]);
+		assert_eq!(hasher.finish(), WyHash::with_seed(0).finish());
+	}
+
+	#[test]
+	fn split_writes_match_single_write() {
+		let data = b"the quick brown fox jumps over the lazy dog";
+
+		let mut single = WyHash::with_seed(1234);
+		single.write(data);
+
+		let mut split = WyHash::with_seed(1234);
+		for chunk in data.chunks(7) {
+			split.write(chunk);
+		}
+
+		assert_eq!(single.finish(), split.finish());
+	}
+
+	#[test]
+	fn different_seeds_diverge() {
+		let mut a = WyHash::with_seed(1);
+		let mut b = WyHash::with_seed(2);
+		a.write(b"wyhash");
+		b.write(b"wyhash");
+		assert_ne!(a.finish(), b.finish());
+	}
+}
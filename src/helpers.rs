@@ -0,0 +1,195 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Minimal-dependency inherent helpers for generating values within a range
+//! and shuffling slices, so `no_std` users don't need to pull in the full
+//! `rand` crate for the common cases.
+//!
+//! This module is only available when the `helpers` feature (on by default)
+//! is enabled.
+use core::ops::{Bound, RangeBounds};
+
+use rand_core::RngCore;
+
+use crate::WyRand;
+
+/// Maps a signed integer into offset-binary form, preserving ordering, so the
+/// unsigned rejection-sampling routine can be reused for signed ranges.
+#[inline]
+fn to_offset_binary(n: i64) -> u64 {
+	(n as u64) ^ (1 << 63)
+}
+
+/// The inverse of [to_offset_binary].
+#[inline]
+fn from_offset_binary(n: u64) -> i64 {
+	(n ^ (1 << 63)) as i64
+}
+
+impl WyRand {
+	/// Draws a `u64` uniformly from `[low, high]` using Lemire's
+	/// multiply-reduce rejection method.
+	///
+	/// ## Panics
+	///
+	/// Panics if `low > high`.
+	fn bounded_u64(&mut self, low: u64, high: u64) -> u64 {
+		assert!(low <= high, "empty range passed to range_*");
+
+		let span = high - low;
+		if span == u64::MAX {
+			// The full `u64` range can't be biased, and `s.wrapping_neg() % s`
+			// below would divide by zero.
+			return self.next_u64();
+		}
+		let s = span + 1;
+
+		let mut m = (self.next_u64() as u128) * (s as u128);
+		let mut lo = m as u64;
+		if lo < s {
+			let threshold = s.wrapping_neg() % s;
+			while lo < threshold {
+				m = (self.next_u64() as u128) * (s as u128);
+				lo = m as u64;
+			}
+		}
+		(m >> 64) as u64 + low
+	}
+
+	/// Shuffles `slice` in place using the Fisher-Yates algorithm.
+	pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+		for i in (1..slice.len()).rev() {
+			let j = self.range_usize(0..=i);
+			slice.swap(i, j);
+		}
+	}
+}
+
+macro_rules! unsigned_range_method {
+	($name:ident, $ty:ty, $doc:literal) => {
+		impl WyRand {
+			#[doc = $doc]
+			///
+			/// ## Panics
+			///
+			/// Panics if `bounds` is empty.
+			pub fn $name(&mut self, bounds: impl RangeBounds<$ty>) -> $ty {
+				let low = match bounds.start_bound() {
+					Bound::Included(&n) => n as u64,
+					Bound::Excluded(&n) => (n as u64)
+						.checked_add(1)
+						.expect("range start overflowed"),
+					Bound::Unbounded => 0,
+				};
+				let high = match bounds.end_bound() {
+					Bound::Included(&n) => n as u64,
+					Bound::Excluded(&n) => (n as u64)
+						.checked_sub(1)
+						.expect("range end underflowed"),
+					Bound::Unbounded => <$ty>::MAX as u64,
+				};
+				self.bounded_u64(low, high) as $ty
+			}
+		}
+	};
+}
+
+macro_rules! signed_range_method {
+	($name:ident, $ty:ty, $doc:literal) => {
+		impl WyRand {
+			#[doc = $doc]
+			///
+			/// ## Panics
+			///
+			/// Panics if `bounds` is empty.
+			pub fn $name(&mut self, bounds: impl RangeBounds<$ty>) -> $ty {
+				let low = match bounds.start_bound() {
+					Bound::Included(&n) => n as i64,
+					Bound::Excluded(&n) => (n as i64)
+						.checked_add(1)
+						.expect("range start overflowed"),
+					Bound::Unbounded => <$ty>::MIN as i64,
+				};
+				let high = match bounds.end_bound() {
+					Bound::Included(&n) => n as i64,
+					Bound::Excluded(&n) => (n as i64)
+						.checked_sub(1)
+						.expect("range end underflowed"),
+					Bound::Unbounded => <$ty>::MAX as i64,
+				};
+				let value = self.bounded_u64(to_offset_binary(low), to_offset_binary(high));
+				from_offset_binary(value) as $ty
+			}
+		}
+	};
+}
+
+unsigned_range_method!(range_u8, u8, "Generates a random `u8` within `bounds`.");
+unsigned_range_method!(range_u16, u16, "Generates a random `u16` within `bounds`.");
+unsigned_range_method!(range_u32, u32, "Generates a random `u32` within `bounds`.");
+unsigned_range_method!(
+	range_u64,
+	u64,
+	"Generates a random `u64` within `bounds`, using Lemire's multiply-reduce rejection method \
+	 for an unbiased result."
+);
+unsigned_range_method!(
+	range_usize,
+	usize,
+	"Generates a random `usize` within `bounds`."
+);
+
+signed_range_method!(range_i8, i8, "Generates a random `i8` within `bounds`.");
+signed_range_method!(range_i16, i16, "Generates a random `i16` within `bounds`.");
+signed_range_method!(range_i32, i32, "Generates a random `i32` within `bounds`.");
+signed_range_method!(range_i64, i64, "Generates a random `i64` within `bounds`.");
+signed_range_method!(range_isize, isize, "Generates a random `isize` within `bounds`.");
+
+#[cfg(test)]
+mod tests {
+	use rand_core::SeedableRng;
+
+	use crate::WyRand;
+
+	#[test]
+	fn range_u64_stays_in_bounds() {
+		let mut rng = WyRand::seed_from_u64(1);
+		for _ in 0..1000 {
+			let n = rng.range_u64(10..20);
+			assert!((10..20).contains(&n));
+		}
+	}
+
+	#[test]
+	fn range_i64_stays_in_bounds() {
+		let mut rng = WyRand::seed_from_u64(2);
+		for _ in 0..1000 {
+			let n = rng.range_i64(-5..=5);
+			assert!((-5..=5).contains(&n));
+		}
+	}
+
+	#[test]
+	fn range_full_width_does_not_panic() {
+		let mut rng = WyRand::seed_from_u64(3);
+		rng.range_u64(..);
+		rng.range_i64(..);
+	}
+
+	#[test]
+	#[should_panic]
+	fn range_empty_panics() {
+		let mut rng = WyRand::seed_from_u64(4);
+		#[allow(clippy::reversed_empty_ranges)]
+		rng.range_u64(10..5);
+	}
+
+	#[test]
+	fn shuffle_is_a_permutation() {
+		let mut rng = WyRand::seed_from_u64(5);
+		let mut values = [0, 1, 2, 3, 4, 5, 6, 7];
+		rng.shuffle(&mut values);
+
+		let mut sorted = values;
+		sorted.sort_unstable();
+		assert_eq!(sorted, [0, 1, 2, 3, 4, 5, 6, 7]);
+	}
+}
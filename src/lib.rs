@@ -47,6 +47,22 @@
 //! println!("Random string: {rand_string}")
 //! ```
 //!
+//! ### Generate a random number and shuffle a slice, without depending on `rand`
+//!
+//! Requires the (default-on) `helpers` feature.
+//!
+//! ```rust,ignore
+//! use rand_core::SeedableRng;
+//! use rand_wyrand::WyRand;
+//!
+//! let mut wyrand = WyRand::from_entropy();
+//! println!("Random number from 1 to 100: {}", wyrand.range_u64(1..=100));
+//!
+//! let mut deck = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+//! wyrand.shuffle(&mut deck);
+//! println!("Shuffled deck: {deck:?}");
+//! ```
+//!
 //! ## License
 //!
 //! `rand-wyrand` is licensed under either the [Apache
@@ -56,6 +72,9 @@
 #![forbid(unsafe_code)]
 #![deny(clippy::perf, clippy::style, clippy::correctness, clippy::complexity)]
 #![allow(clippy::tabs_in_doc_comments)]
+#[cfg(feature = "std")]
+extern crate std;
+
 use core::fmt::Debug;
 
 use rand_core::{impls::fill_bytes_via_next, Error, RngCore, SeedableRng};
@@ -63,6 +82,57 @@ use rand_core::{impls::fill_bytes_via_next, Error, RngCore, SeedableRng};
 #[cfg(feature = "serde1")]
 use serde::{Deserialize, Serialize};
 
+mod util;
+
+#[cfg(feature = "hash")]
+mod hash;
+
+#[cfg(feature = "hash")]
+pub use hash::{WyHash, WyHashBuilder};
+
+#[cfg(feature = "helpers")]
+mod helpers;
+
+#[cfg(feature = "std")]
+mod thread;
+
+#[cfg(feature = "std")]
+pub use thread::{reseed_thread_rng, with_thread_rng};
+
+use util::{wymix, P0 as WEYL};
+
+/// The multiplicand XORed into the state before mixing.
+///
+/// This differs between the final v4 revision (gated behind the `legacy_v4`
+/// feature) and the v4.2 revision used by default, since the two revisions
+/// changed the constant alongside the mixing step itself.
+#[cfg(not(feature = "legacy_v4"))]
+const MULTIPLIER: u64 = util::P1;
+#[cfg(feature = "legacy_v4")]
+const MULTIPLIER: u64 = 0x9e3779b97f4a7c15;
+
+/// The single mixing step shared by both revisions of `next_u64`.
+///
+/// The v4.2 revision (default) simply XOR-folds the 128-bit product of `a`
+/// and `b`. The v4 revision (behind the `legacy_v4` feature) instead applies
+/// a "condom" over that fold, XORing the pre-multiply operands back in so a
+/// multiply that happens to produce a correlated zero doesn't collapse the
+/// state.
+#[cfg(not(feature = "legacy_v4"))]
+#[inline]
+fn mix_step(a: u64, b: u64) -> u64 {
+	wymix(a, b)
+}
+
+#[cfg(feature = "legacy_v4")]
+#[inline]
+fn mix_step(a: u64, b: u64) -> u64 {
+	let t: u128 = (a as u128).wrapping_mul(b as u128);
+	let lo = t as u64;
+	let hi = (t >> 64) as u64;
+	(a ^ lo) ^ (b ^ hi)
+}
+
 /// An instance of the [WyRand](https://github.com/wangyi-fudan/wyhash) random number generator.
 ///
 /// While not cryptographically secure, WyRand is solid enough to pass
@@ -108,10 +178,85 @@ use serde::{Deserialize, Serialize};
 /// 	.collect();
 /// println!("Random string: {rand_string}")
 /// ```
+///
+/// ### Generate a random number and shuffle a slice, without depending on `rand`
+///
+/// Requires the (default-on) `helpers` feature.
+///
+/// ```rust,ignore
+/// use rand_core::SeedableRng;
+/// use rand_wyrand::WyRand;
+///
+/// let mut wyrand = WyRand::from_entropy();
+/// println!("Random number from 1 to 100: {}", wyrand.range_u64(1..=100));
+///
+/// let mut deck = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+/// wyrand.shuffle(&mut deck);
+/// println!("Shuffled deck: {deck:?}");
+/// ```
 #[derive(Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
 pub struct WyRand {
 	seed: u64,
+	/// The Weyl sequence increment this instance advances by.
+	///
+	/// This is always odd, so that every stream visits all `2^64` states
+	/// before repeating. It defaults to the canonical Weyl constant, and is
+	/// only overridden by [WyRand::split] and [WyRand::with_stream] to give
+	/// independent instances disjoint sequences.
+	#[cfg_attr(
+		feature = "serde1",
+		serde(default = "default_gamma", skip_serializing_if = "is_default_gamma")
+	)]
+	gamma: u64,
+}
+
+#[cfg(feature = "serde1")]
+fn default_gamma() -> u64 {
+	WEYL
+}
+
+#[cfg(feature = "serde1")]
+fn is_default_gamma(gamma: &u64) -> bool {
+	*gamma == WEYL
+}
+
+impl WyRand {
+	/// Splits off a new, independent [WyRand] stream from this one.
+	///
+	/// The child is seeded and given a fresh odd Weyl increment derived from
+	/// this instance, so the two instances step through the state space with
+	/// different increments, and so in a different order. This is the cheapest way to hand out independent generators to
+	/// parallel workers without risking correlated or colliding seeds.
+	#[must_use]
+	pub fn split(&mut self) -> Self {
+		let seed = self.next_u64();
+		let raw_gamma = self.next_u64();
+		let gamma = wymix(raw_gamma, raw_gamma ^ MULTIPLIER) | 1;
+		Self { seed, gamma }
+	}
+
+	/// Creates a [WyRand] seeded with `seed`, advancing through the `stream`th
+	/// Weyl sequence rather than the canonical one.
+	///
+	/// The odd increment is derived by mixing `stream`, so different `stream`
+	/// values give different increments (and so different orders through the
+	/// state space) except in the rare case where two values mix to the same
+	/// increment. This is a SplitMix-style way to hand out independent streams
+	/// up front instead of via [WyRand::split].
+	#[inline]
+	#[must_use]
+	pub fn with_stream(seed: u64, stream: u64) -> Self {
+		let gamma = wymix(stream, stream ^ MULTIPLIER) | 1;
+		Self { seed, gamma }
+	}
+
+	/// Advances the state as if `next_u64` had been called `n` times, without
+	/// actually generating the skipped outputs.
+	#[inline]
+	pub fn jump(&mut self, n: u64) {
+		self.seed = self.seed.wrapping_add(self.gamma.wrapping_mul(n));
+	}
 }
 
 impl RngCore for WyRand {
@@ -122,9 +267,8 @@ impl RngCore for WyRand {
 
 	#[inline]
 	fn next_u64(&mut self) -> u64 {
-		self.seed = self.seed.wrapping_add(0xa0761d6478bd642f);
-		let t: u128 = (self.seed as u128).wrapping_mul((self.seed ^ 0xe7037ed1a0b428db) as u128);
-		(t.wrapping_shr(64) ^ t) as u64
+		self.seed = self.seed.wrapping_add(self.gamma);
+		mix_step(self.seed, self.seed ^ MULTIPLIER)
 	}
 
 	#[inline]
@@ -149,7 +293,10 @@ impl SeedableRng for WyRand {
 
 	#[inline]
 	fn seed_from_u64(state: u64) -> Self {
-		Self { seed: state }
+		Self {
+			seed: state,
+			gamma: WEYL,
+		}
 	}
 
 	#[inline]
@@ -180,6 +327,58 @@ mod tests {
 		assert_eq!(format!("{:?}", rng), "WyRand");
 	}
 
+	#[test]
+	#[cfg(not(feature = "legacy_v4"))]
+	fn v4_2_reference_vector() {
+		let mut rng = WyRand::seed_from_u64(12345);
+
+		assert_eq!(rng.next_u64(), 3765284116619136123);
+		assert_eq!(rng.next_u64(), 14381291168274089364);
+	}
+
+	#[test]
+	#[cfg(feature = "legacy_v4")]
+	fn v4_reference_vector() {
+		let mut rng = WyRand::seed_from_u64(12345);
+
+		assert_eq!(rng.next_u64(), 2207945615480718762);
+		assert_eq!(rng.next_u64(), 421716264050588823);
+	}
+
+	#[test]
+	fn split_produces_disjoint_gammas() {
+		let mut parent = WyRand::seed_from_u64(1);
+
+		let a = parent.split();
+		let b = parent.split();
+
+		assert_ne!(a.gamma, b.gamma);
+		assert_eq!(a.gamma % 2, 1);
+		assert_eq!(b.gamma % 2, 1);
+	}
+
+	#[test]
+	fn with_stream_is_independent_of_canonical() {
+		let mut canonical = WyRand::seed_from_u64(42);
+		let mut streamed = WyRand::with_stream(42, 7);
+
+		assert_ne!(streamed.gamma, canonical.gamma);
+		assert_ne!(canonical.next_u64(), streamed.next_u64());
+	}
+
+	#[test]
+	fn jump_matches_repeated_next_u64() {
+		let mut stepped = WyRand::seed_from_u64(99);
+		let mut jumped = WyRand::seed_from_u64(99);
+
+		for _ in 0..5 {
+			stepped.next_u64();
+		}
+		jumped.jump(5);
+
+		assert_eq!(stepped.next_u64(), jumped.next_u64());
+	}
+
 	#[cfg(feature = "serde1")]
 	#[test]
 	fn serde_tokens() {
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! An opt-in, entropy-seeded thread-local [WyRand], for scripting-style
+//! one-off random values without threading an RNG through every call site.
+//!
+//! This module is only available when the `std` feature is enabled.
+use std::cell::RefCell;
+
+use rand_core::SeedableRng;
+
+use crate::WyRand;
+
+std::thread_local! {
+	static THREAD_WYRAND: RefCell<WyRand> = RefCell::new(WyRand::from_entropy());
+}
+
+/// Runs `f` with mutable access to the current thread's [WyRand] instance,
+/// lazily seeding it from entropy on first use.
+///
+/// Because the instance lives in a `thread_local!`, it can't be handed out
+/// as a plain reference without unsafe code, so access goes through this
+/// closure instead.
+///
+/// ## Examples
+///
+/// ```rust
+/// use rand_core::RngCore;
+/// use rand_wyrand::with_thread_rng;
+///
+/// let n: u64 = with_thread_rng(RngCore::next_u64);
+/// println!("Random number: {n}");
+/// ```
+pub fn with_thread_rng<R>(f: impl FnOnce(&mut WyRand) -> R) -> R {
+	THREAD_WYRAND.with(|rng| f(&mut rng.borrow_mut()))
+}
+
+/// Replaces the current thread's [WyRand] instance with a freshly
+/// entropy-seeded one.
+pub fn reseed_thread_rng() {
+	THREAD_WYRAND.with(|rng| *rng.borrow_mut() = WyRand::from_entropy());
+}
+
+#[cfg(test)]
+mod tests {
+	use rand_core::RngCore;
+
+	use super::*;
+
+	#[test]
+	fn with_thread_rng_advances_the_shared_instance() {
+		let (first, second) = (
+			with_thread_rng(WyRand::next_u64),
+			with_thread_rng(WyRand::next_u64),
+		);
+
+		assert_ne!(first, second);
+	}
+
+	#[test]
+	fn reseed_thread_rng_does_not_panic() {
+		reseed_thread_rng();
+		with_thread_rng(WyRand::next_u64);
+	}
+}
@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+//! Internal helpers shared between the PRNG and (optionally) the hasher.
+
+/// The first secret constant shared by the wyrand (as the Weyl increment) and
+/// wyhash (XORed into the seed up front) algorithms.
+pub(crate) const P0: u64 = 0xa0761d6478bd642f;
+
+/// The second secret constant shared by the wyrand (as the v4.2 multiplicand)
+/// and wyhash (XORed into every block) algorithms.
+///
+/// Unused (and so allowed to go dead) when the `hash` feature is disabled and
+/// the `legacy_v4` feature is enabled, since neither consumer references it then.
+#[allow(dead_code)]
+pub(crate) const P1: u64 = 0xe7037ed1a0b428db;
+
+/// The core wyhash/wyrand mixing step: XOR-folds the 128-bit product of `a`
+/// and `b` down into a single `u64`.
+#[inline]
+pub(crate) fn wymix(a: u64, b: u64) -> u64 {
+	let t: u128 = (a as u128).wrapping_mul(b as u128);
+	(t.wrapping_shr(64) ^ t) as u64
+}